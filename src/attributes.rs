@@ -1,11 +1,16 @@
-use crate::Pointer;
+use crate::{Pointer, ULen};
 
 /// Governs behaviour of EnvironmentAttribute
 #[repr(i32)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum EnvironmentAttribute {
     OdbcVersion = 200,
+    /// `SQL_ATTR_CONNECTION_POOLING`. Enables or disables connection pooling at the environment
+    /// level, see [`AttrConnectionPooling`]. This attribute is process wide. It is set by calling
+    /// `SQLSetEnvAttr` with a null environment handle, before any environment is allocated.
     ConnectionPooling = 201,
+    /// `SQL_ATTR_CP_MATCH`. Determines how a connection is chosen from a connection pool, see
+    /// [`AttrCpMatch`]. Set on an allocated environment handle.
     CpMatch = 202,
     // This attribute was commented out because there is no mention of it in the ODBC specification
     // nor does this attribute exist in unixODBC or iODBC implementations. This attribute exists in
@@ -99,6 +104,253 @@ impl From<AttrCpMatch> for Pointer {
     }
 }
 
+/// Possible values for `ConnectionAttribute::AUTO_COMMIT` set with [`crate::SQLSetConnectAttr`]
+/// to define whether statements are committed automatically or within manual transactions.
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AttrAutoCommit {
+    /// The driver uses manual-commit mode, and the application must explicitly commit or roll back
+    /// transactions with [`crate::SQLEndTran`].
+    Off = 0,
+    /// The driver uses autocommit mode. Each statement is committed immediately after it is
+    /// executed. This is the default. Any open transactions on the connection are committed when
+    /// `AUTO_COMMIT` is set to `On` to change from manual-commit mode to autocommit mode.
+    On = 1,
+}
+
+/// Autocommit is enabled by default
+impl Default for AttrAutoCommit {
+    fn default() -> Self {
+        AttrAutoCommit::On
+    }
+}
+
+impl From<AttrAutoCommit> for Pointer {
+    fn from(source: AttrAutoCommit) -> Pointer {
+        source as u32 as Pointer
+    }
+}
+
+/// Possible values for `ConnectionAttribute::ACCESS_MODE` set with [`crate::SQLSetConnectAttr`].
+///
+/// This is a hint to the driver. It does not guarantee that the data source rejects statements
+/// which are not read-only.
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AttrAccessMode {
+    /// `SQL_MODE_READ_WRITE`. This is the default.
+    ReadWrite = 0,
+    /// `SQL_MODE_READ_ONLY`. Used by the driver or data source as an indicator that the connection
+    /// is not required to support SQL statements that cause updates to occur. This mode can be
+    /// used to optimize locking strategies, transaction management, or other areas as appropriate
+    /// to the driver or data source.
+    ReadOnly = 1,
+}
+
+/// Connections are read-write by default
+impl Default for AttrAccessMode {
+    fn default() -> Self {
+        AttrAccessMode::ReadWrite
+    }
+}
+
+impl From<AttrAccessMode> for Pointer {
+    fn from(source: AttrAccessMode) -> Pointer {
+        source as u32 as Pointer
+    }
+}
+
+/// Possible values for `ConnectionAttribute::TRACE` set with [`crate::SQLSetConnectAttr`] to
+/// toggle tracing of the driver manager.
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AttrTrace {
+    /// `SQL_OPT_TRACE_OFF`. This is the default.
+    Off = 0,
+    /// `SQL_OPT_TRACE_ON`. Function calls are written to the file set with
+    /// `ConnectionAttribute::TRACE_FILE`.
+    On = 1,
+}
+
+/// Tracing is turned off by default
+impl Default for AttrTrace {
+    fn default() -> Self {
+        AttrTrace::Off
+    }
+}
+
+impl From<AttrTrace> for Pointer {
+    fn from(source: AttrTrace) -> Pointer {
+        source as u32 as Pointer
+    }
+}
+
+/// Possible values for `StatementAttribute::AsyncEnable` set with [`crate::SQLSetStmtAttr`], or
+/// `ConnectionAttribute::ASYNC_ENABLE` set with [`crate::SQLSetConnectAttr`].
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AttrAsyncEnable {
+    /// `SQL_ASYNC_ENABLE_OFF`. Functions are executed synchronously. This is the default.
+    Off = 0,
+    /// `SQL_ASYNC_ENABLE_ON`. Functions which support asynchronous execution return
+    /// `STILL_EXECUTING` while they are processed. The application then polls by calling the same
+    /// function with the same arguments until it returns something else.
+    On = 1,
+}
+
+/// Statements are executed synchronously by default
+impl Default for AttrAsyncEnable {
+    fn default() -> Self {
+        AttrAsyncEnable::Off
+    }
+}
+
+impl From<AttrAsyncEnable> for Pointer {
+    fn from(source: AttrAsyncEnable) -> Pointer {
+        source as u32 as Pointer
+    }
+}
+
+/// Possible values for `StatementAttribute::NoScan` set with [`crate::SQLSetStmtAttr`].
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AttrNoScan {
+    /// `SQL_NOSCAN_OFF`. The driver scans SQL strings for escape sequences. This is the default.
+    Off = 0,
+    /// `SQL_NOSCAN_ON`. The driver does not scan SQL strings for escape sequences. Instead, the
+    /// driver sends the statement directly to the data source.
+    On = 1,
+}
+
+/// Escape sequences are scanned for by default
+impl Default for AttrNoScan {
+    fn default() -> Self {
+        AttrNoScan::Off
+    }
+}
+
+impl From<AttrNoScan> for Pointer {
+    fn from(source: AttrNoScan) -> Pointer {
+        source as u32 as Pointer
+    }
+}
+
+/// Possible values for `StatementAttribute::CursorType` set with [`crate::SQLSetStmtAttr`]. Must be
+/// set before the statement is executed.
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AttrCursorType {
+    /// `SQL_CURSOR_FORWARD_ONLY`. The cursor only scrolls forward. This is the default.
+    ForwardOnly = 0,
+    /// `SQL_CURSOR_KEYSET_DRIVEN`. The driver saves and uses the keys for the number of rows
+    /// specified in `StatementAttribute::KeysetSize`.
+    KeysetDriven = 1,
+    /// `SQL_CURSOR_DYNAMIC`. The driver saves and uses only the keys for the rows in the rowset.
+    Dynamic = 2,
+    /// `SQL_CURSOR_STATIC`. The data in the result set is static.
+    Static = 3,
+}
+
+/// Cursors are forward only by default
+impl Default for AttrCursorType {
+    fn default() -> Self {
+        AttrCursorType::ForwardOnly
+    }
+}
+
+impl From<AttrCursorType> for Pointer {
+    fn from(source: AttrCursorType) -> Pointer {
+        source as u32 as Pointer
+    }
+}
+
+/// Possible values for `StatementAttribute::Concurrency` set with [`crate::SQLSetStmtAttr`]. Must
+/// be set before the statement is executed.
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AttrConcurrency {
+    /// `SQL_CONCUR_READ_ONLY`. Cursor is read-only. No updates are allowed. This is the default.
+    ReadOnly = 1,
+    /// `SQL_CONCUR_LOCK`. Cursor uses the lowest level of locking sufficient to ensure that the
+    /// row can be updated.
+    Lock = 2,
+    /// `SQL_CONCUR_ROWVER`. Cursor uses optimistic concurrency control, comparing row versions
+    /// such as SQLBase ROWID or Sybase TIMESTAMP.
+    RowVer = 3,
+    /// `SQL_CONCUR_VALUES`. Cursor uses optimistic concurrency control, comparing values.
+    Values = 4,
+}
+
+/// Cursors are read-only by default
+impl Default for AttrConcurrency {
+    fn default() -> Self {
+        AttrConcurrency::ReadOnly
+    }
+}
+
+impl From<AttrConcurrency> for Pointer {
+    fn from(source: AttrConcurrency) -> Pointer {
+        source as u32 as Pointer
+    }
+}
+
+/// Possible values for `StatementAttribute::UseBookmarks` set with [`crate::SQLSetStmtAttr`]. Must
+/// be set before the statement is executed.
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AttrUseBookmarks {
+    /// `SQL_UB_OFF`. Bookmarks are not used. This is the default.
+    Off = 0,
+    // Fixed length bookmarks are deprecated since ODBC 3
+    // SQL_UB_FIXED = 1,
+    /// `SQL_UB_VARIABLE`. Variable-length bookmarks are used with the result set. They are
+    /// retrieved as column 0.
+    Variable = 2,
+}
+
+/// Bookmarks are turned off by default
+impl Default for AttrUseBookmarks {
+    fn default() -> Self {
+        AttrUseBookmarks::Off
+    }
+}
+
+impl From<AttrUseBookmarks> for Pointer {
+    fn from(source: AttrUseBookmarks) -> Pointer {
+        source as u32 as Pointer
+    }
+}
+
+/// Possible values for `StatementAttribute::CursorSensitivity` set with
+/// [`crate::SQLSetStmtAttr`]. Must be set before the statement is executed.
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AttrCursorSensitivity {
+    /// `SQL_UNSPECIFIED`. It is unspecified what the cursor type is and whether cursors on the
+    /// statement handle make visible the changes made to a result set by another cursor. This is
+    /// the default.
+    Unspecified = 0,
+    /// `SQL_INSENSITIVE`. All cursors on the statement handle show the result set without
+    /// reflecting any changes made to it by any other cursor.
+    Insensitive = 1,
+    /// `SQL_SENSITIVE`. All cursors on the statement handle make visible all changes made to a
+    /// result set by another cursor.
+    Sensitive = 2,
+}
+
+/// Sensitivity is unspecified by default
+impl Default for AttrCursorSensitivity {
+    fn default() -> Self {
+        AttrCursorSensitivity::Unspecified
+    }
+}
+
+impl From<AttrCursorSensitivity> for Pointer {
+    fn from(source: AttrCursorSensitivity) -> Pointer {
+        source as u32 as Pointer
+    }
+}
+
 /// Statement attributes are characteristics of the statement. For example, whether to use bookmarks
 /// and what kind of cursor to use with the statement's result set are statement attributes.
 ///
@@ -125,41 +377,66 @@ pub enum StatementAttribute {
     ImpParamDesc = 10013,
     /// SQL_ATTR_CURSOR_SCROLLABLE
     CursorScrollable = -1,
-    /// SQL_ATTR_CURSOR_SENSITIVITY
+    /// SQL_ATTR_CURSOR_SENSITIVITY. Whether cursors see changes made by other cursors, see
+    /// [`AttrCursorSensitivity`].
     CursorSensitivity = -2,
 
     // Extensions
-    /// SQL_ATTR_ASYNC_ENABLE
+    /// SQL_ATTR_ASYNC_ENABLE. Enables polling based asynchronous execution, see
+    /// [`AttrAsyncEnable`].
     AsyncEnable = 4,
-    /// SQL_ATTR_CONCURRENCY
+    /// SQL_ATTR_CONCURRENCY. Specifies the cursor concurrency, see [`AttrConcurrency`].
     Concurrency = 7,
-    /// SQL_ATTR_CURSOR_TYPE
+    /// SQL_ATTR_CURSOR_TYPE. Specifies the cursor type, see [`AttrCursorType`].
     CursorType = 6,
     /// SQL_ATTR_ENABLE_AUTO_IPD
     EnableAutoIpd = 15,
-    /// SQL_ATTR_FETCH_BOOKMARK_PTR
+    /// SQL_ATTR_FETCH_BOOKMARK_PTR. Points to the bookmark used by `SQLFetchScroll` together with
+    /// `FetchOrientation::Bookmark`.
     FetchBookmarkPtr = 16,
-    /// SQL_ATTR_KEYSET_SIZE
+    /// SQL_ATTR_KEYSET_SIZE. A `ULen` specifying the number of rows in the keyset for a keyset
+    /// driven cursor. If zero (the default), the cursor is fully keyset driven. Otherwise the
+    /// cursor is mixed, keyset driven within the keyset and dynamic outside of it.
     KeysetSize = 8,
-    /// SQL_ATTR_MAX_LENGTH
+    /// SQL_ATTR_MAX_LENGTH. A `ULen` value that specifies the maximum amount of data that the
+    /// driver returns from a character or binary column. If the value is less than the length of
+    /// the available data, `SQLFetch` or `SQLGetData` truncates the data and returns `SUCCESS`. If
+    /// zero (the default), the driver attempts to return all available data.
     MaxLength = 3,
-    /// SQL_ATTR_MAX_ROWS
+    /// SQL_ATTR_MAX_ROWS. A `ULen` value corresponding to the maximum number of rows to return to
+    /// the application for a `SELECT` statement. If zero (the default), the driver returns all
+    /// rows.
     MaxRows = 1,
-    /// SQL_ATTR_NOSCAN
+    /// SQL_ATTR_NOSCAN. Whether the driver scans SQL strings for escape sequences, see
+    /// [`AttrNoScan`].
     NoScan = 2,
-    /// SQL_ATTR_PARAM_BIND_OFFSET_PTR
+    /// SQL_ATTR_PARAM_BIND_OFFSET_PTR. Points to a `ULen` offset added to the addresses of all
+    /// parameter buffers and indicators. Together with row-wise binding via `ParamBindType` this
+    /// allows the same bindings to be reused for different arrays of `#[repr(C)]` records.
     ParamBindOffsetPtr = 17,
-    /// SQL_ATTR_PARAM_BIND_TYPE
+    /// SQL_ATTR_PARAM_BIND_TYPE. Either [`PARAM_BIND_BY_COLUMN`] for column-wise binding of
+    /// parameter arrays, or the size of the structure holding one set of parameters for row-wise
+    /// binding.
     ParamBindType = 18,
     /// SQL_ATTR_PARAM_OPERATION_PTR
     ParamOpterationPtr = 19,
-    /// SQL_ATTR_PARAM_STATUS_PTR
+    /// SQL_ATTR_PARAM_STATUS_PTR. Points to an array of [`crate::ParamStatus`] with one element for
+    /// each set of parameters, in which the driver reports the outcome for that set.
     ParamStatusPtr = 20,
-    /// SQL_ATTR_PARAMS_PROCESSED_PTR
+    /// SQL_ATTR_PARAMS_PROCESSED_PTR. Points to a `ULen` buffer in which the driver returns the
+    /// number of sets of parameters that have been processed, including error sets. The buffer
+    /// must stay valid until `SQLExecute` or `SQLExecDirect`, and any subsequent `SQLParamData`
+    /// loop, has returned. If the call to `SQLExecute` or `SQLExecDirect` that fills in the buffer
+    /// does not return `SUCCESS` or `SUCCESS_WITH_INFO`, the contents of the buffer are undefined.
     ParamsProcessedPtr = 21,
-    // SQL_ATTR_PARAMSET_SIZE
+    /// SQL_ATTR_PARAMSET_SIZE. Number of values for each parameter. If greater than 1, the
+    /// parameter buffers bound with `SQLBindParameter` point to arrays, allowing for many sets of
+    /// parameters to be sent to the data source with a single call to `SQLExecute`.
     ParamsetSize = 22,
-    /// SQL_ATTR_QUERY_TIMEOUT
+    /// SQL_ATTR_QUERY_TIMEOUT. A `ULen` value corresponding to the number of seconds to wait for an
+    /// SQL statement to execute before returning to the application. If zero (the default), there
+    /// is no timeout. Once the timeout expires the driver cancels the statement and returns
+    /// SQLSTATE HYT00 (Timeout expired).
     QueryTimeout = 0,
     /// SQL_ATTR_RETRIEVE_DATA
     RetrieveData = 11,
@@ -167,19 +444,25 @@ pub enum StatementAttribute {
     RowBindOffsetPtr = 23,
     /// SQL_ATTR_ROW_BIND_TYPE
     RowBindType = 5,
-    /// SQL_ATTR_ROW_NUMBER `GetStmtAttr`
+    /// SQL_ATTR_ROW_NUMBER `GetStmtAttr`. A `ULen` value that is the (one based) number of the
+    /// current row in the entire result set. If the number of the current row cannot be determined
+    /// or there is no current row, the driver returns 0.
     RowNumber = 14,
     /// SQL_ATTR_ROW_OPERATION_PTR
     RowOperationPtr = 24,
-    /// SQL_ATTR_ROW_STATUS_PTR
+    /// SQL_ATTR_ROW_STATUS_PTR. Points to an array of [`crate::RowStatus`] with one element for
+    /// each row of the rowset, in which the driver reports the status of that row after each fetch.
     RowStatusPtr = 25,
-    /// SQL_ATTR_ROWS_FETCHED_PTR
+    /// SQL_ATTR_ROWS_FETCHED_PTR. Points to a `ULen` buffer in which the driver returns the number
+    /// of rows fetched after a call to `SQLFetch` or `SQLFetchScroll`. The buffer must stay valid
+    /// as long as rows are fetched, or until the attribute is reset to null.
     RowsFetchedPtr = 26,
     /// SQL_ATTR_ROW_ARRAY_SIZE
     RowArraySize = 27,
     /// SQL_ATTR_SIMULATE_CURSOR
     SimulateCursor = 10,
-    /// SQL_ATTR_USE_BOOKMARKS
+    /// SQL_ATTR_USE_BOOKMARKS. Whether bookmarks are used with the cursor, see
+    /// [`AttrUseBookmarks`].
     UseBookmarks = 12,
     #[cfg(feature = "odbc_version_3_80")]
     /// SQL_ATTR_ASYNC_STMT_EVENT
@@ -199,3 +482,7 @@ pub enum StatementAttribute {
     /// SQL_ATTR_METADATA_ID
     MetadataId = 10014,
 }
+
+/// SQL_PARAM_BIND_BY_COLUMN. Value for `StatementAttribute::ParamBindType` selecting column-wise
+/// binding of parameter arrays. This is the default.
+pub const PARAM_BIND_BY_COLUMN: ULen = 0;
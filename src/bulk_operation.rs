@@ -2,8 +2,15 @@
 #[repr(u16)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum BulkOperation {
+    /// `SQL_ADD`. Inserts new rows from the bound buffers.
     Add = 4,
+    /// `SQL_UPDATE_BY_BOOKMARK`. Updates the rows identified by the bookmarks in the bound
+    /// bookmark column with the values of the bound buffers.
     UpdateByBookmark = 5,
+    /// `SQL_DELETE_BY_BOOKMARK`. Deletes the rows identified by the bookmarks in the bound
+    /// bookmark column.
     DeleteByBookmark = 6,
+    /// `SQL_FETCH_BY_BOOKMARK`. Fetches the rows identified by the bookmarks in the bound bookmark
+    /// column into the bound buffers.
     FetchByBookmark = 7,
 }
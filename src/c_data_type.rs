@@ -80,7 +80,13 @@ pub enum CDataType {
     SsTimestampOffset = C_TYPES_EXTENDED + 1,
 }
 
-#[cfg(windows)]
-pub use CDataType::ULong as UBigInt;
-#[cfg(not(windows))]
+/// `SQL_C_VARBOOKMARK`. C type of variable-length bookmarks, used with
+/// `AttrUseBookmarks::Variable`. The size of a bookmark can be determined by calling
+/// `SQLColAttribute` with `Desc::OctetLength` for column 0.
+pub use CDataType::Binary as VarBookmark;
+/// `SQL_C_BOOKMARK`. C type of fixed length bookmarks.
+#[cfg(all(windows, target_pointer_width = "64"))]
+pub use CDataType::UBigInt as Bookmark;
+/// `SQL_C_BOOKMARK`. C type of fixed length bookmarks.
+#[cfg(not(all(windows, target_pointer_width = "64")))]
 pub use CDataType::ULong as Bookmark;
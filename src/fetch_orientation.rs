@@ -9,6 +9,9 @@ pub enum FetchOrientation {
     Prior = 4,
     Absolute = 5,
     Relative = 6,
+    /// `SQL_FETCH_BOOKMARK`. Fetch the rowset starting at the bookmark pointed to by
+    /// `StatementAttribute::FetchBookmarkPtr`, offset by `fetch_offset` rows.
+    Bookmark = 8,
     // additional SQLDataSources fetch directions
     FirstUser = 31,
     FirstSystem = 32,
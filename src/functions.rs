@@ -1,8 +1,8 @@
 use crate::{
     BulkOperation, CDataType, Char, CompletionType, ConnectionAttribute, Desc, DriverConnectOption,
     EnvironmentAttribute, FetchOrientation, FreeStmtOption, HDbc, HDesc, HEnv, HStmt, HWnd, Handle,
-    HandleType, InfoType, Integer, Len, Nullability, ParamType, Pointer, RetCode, SmallInt,
-    SqlDataType, SqlReturn, StatementAttribute, ULen, USmallInt, WChar,
+    HandleType, InfoType, Integer, Len, Lock, Nullability, Operation, ParamType, Pointer, RetCode,
+    SetPosIRow, SmallInt, SqlDataType, SqlReturn, StatementAttribute, ULen, USmallInt, WChar,
 };
 
 pub static mut NUM_ENVIRONMENT: u32 = 0;
@@ -123,6 +123,24 @@ extern "system" {
         string_length_ptr: *mut SmallInt,
     ) -> SqlReturn;
 
+    /// Returns the current value of a field of a record of the diagnostic data structure
+    /// (associated with a specified handle) that contains error, warning, and status information.
+    ///
+    /// Note:
+    /// `diag_identifier` is either [`crate::HeaderDiagnosticIdentifier`] or
+    /// [`crate::DynamicDiagnosticIdentifier`]
+    /// # Returns
+    /// `SUCCESS`, `SUCCESS_WITH_INFO`, `ERROR`, `INVALID_HANDLE`, or `SQL_NO_DATA`.
+    pub fn SQLGetDiagField(
+        handle_type: HandleType,
+        handle: Handle,
+        record_number: SmallInt,
+        diag_identifier: SmallInt,
+        diag_info_ptr: Pointer,
+        buffer_length: SmallInt,
+        string_length_ptr: *mut SmallInt,
+    ) -> SqlReturn;
+
     /// Executes a preparable statement, using the current values of the parameter marker variables
     /// if any parameters exist in the statement. This is the fastest way to submit an SQL
     /// statement for one-time execution
@@ -171,7 +189,15 @@ extern "system" {
     /// `INVALID_HANDLE`, or `SQL_PARAM_DATA_AVAILABLE`.
     pub fn SQLMoreResults(statement_handle: HStmt) -> SqlReturn;
 
-    // Can be used since odbc version 3.8 to stream results
+    /// Retrieves data for a single column in the result set or for a single parameter after
+    /// `SQLParamData` returns `PARAM_DATA_AVAILABLE`. It can be called multiple times to retrieve
+    /// variable-length data in parts. Columns retrieved this way need not be bound.
+    ///
+    /// Retrieving streamed output parameters after `PARAM_DATA_AVAILABLE` requires ODBC 3.8.
+    /// Retrieving columns is supported by all ODBC versions.
+    ///
+    /// # Returns
+    /// `SUCCESS`, `SUCCESS_WITH_INFO`, `NO_DATA`, `STILL_EXECUTING`, `ERROR`, or `INVALID_HANDLE`.
     pub fn SQLGetData(
         statement_handle: HStmt,
         col_or_param_num: USmallInt,
@@ -420,6 +446,22 @@ extern "system" {
     /// `SUCCESS`, `SUCCESS_WITH_INFO`, `SQL_NEED_DATA`, `SQL_STILL_EXECUTING`, `ERROR`, or `INVALID_HANDLE`.
     pub fn SQLBulkOperations(statement_handle: HStmt, operation: BulkOperation) -> SqlReturn;
 
+    /// Sets the cursor position in a rowset and allows an application to refresh data in the
+    /// rowset or to update or delete data in the result set.
+    ///
+    /// `row_number` is the one based position of the row in the rowset. If it is 0, the operation
+    /// applies to every row in the rowset.
+    ///
+    /// # Returns
+    /// `SUCCESS`, `SUCCESS_WITH_INFO`, `NEED_DATA`, `STILL_EXECUTING`, `ERROR`, or
+    /// `INVALID_HANDLE`.
+    pub fn SQLSetPos(
+        statement_handle: HStmt,
+        row_number: SetPosIRow,
+        operation: Operation,
+        lock_type: Lock,
+    ) -> SqlReturn;
+
     /// Cancels the processing on a statement.
     ///
     /// # Returns
@@ -451,6 +493,32 @@ extern "system" {
         text_length: Integer,
     ) -> SqlReturn;
 
+    /// Returns the SQL string as modified by the driver. Does not execute the SQL statement.
+    ///
+    /// # Returns
+    /// `SUCCESS`, `SUCCESS_WITH_INFO`, `ERROR`, or `INVALID_HANDLE`.
+    pub fn SQLNativeSql(
+        connection_handle: HDbc,
+        in_statement_text: *const Char,
+        in_statement_len: Integer,
+        out_statement_text: *mut Char,
+        buffer_len: Integer,
+        out_statement_len: *mut Integer,
+    ) -> SqlReturn;
+
+    /// Returns the SQL string as modified by the driver. Does not execute the SQL statement.
+    ///
+    /// # Returns
+    /// `SUCCESS`, `SUCCESS_WITH_INFO`, `ERROR`, or `INVALID_HANDLE`.
+    pub fn SQLNativeSqlW(
+        connection_handle: HDbc,
+        in_statement_text: *const WChar,
+        in_statement_len: Integer,
+        out_statement_text: *mut WChar,
+        buffer_len: Integer,
+        out_statement_len: *mut Integer,
+    ) -> SqlReturn;
+
     /// Executes a prepared statement, using the current values of the parameter marker variables
     /// if any paramater markers exis in the statement.
     ///
@@ -495,6 +563,22 @@ extern "system" {
         out_buffer_length: *mut SmallInt,
     ) -> SqlReturn;
 
+    /// SQLBrowseConnect supports an iterative method of discovering and enumerating the attributes
+    /// and attribute values required to connect to a data source.
+    /// Each call to SQLBrowseConnect returns successive levels of attributes and attribute values.
+    ///
+    /// # Returns
+    /// `SUCCESS`, `SUCCESS_WITH_INFO`, `NEED_DATA`, `ERROR`, `INVALID_HANDLE`, or
+    /// `STILL_EXECUTING`.
+    pub fn SQLBrowseConnect(
+        connection_handle: HDbc,
+        in_connection_string: *const Char,
+        string_length: SmallInt,
+        out_connection_string: *mut Char,
+        buffer_length: SmallInt,
+        out_buffer_length: *mut SmallInt,
+    ) -> SqlReturn;
+
     /// Returns descriptor information for a column in a result set. Descriptor information is
     /// returned as a character string, a descriptor-dependent value, or an integer value.
     ///
@@ -574,6 +658,41 @@ extern "system" {
         name_length_ptr: *mut SmallInt,
     ) -> SqlReturn;
 
+    /// Returns the cursor name associated with a specified statement.
+    ///
+    /// # Returns
+    /// `SUCCESS`, `SUCCESS_WITH_INFO`, `ERROR`, or `INVALID_HANDLE`.
+    pub fn SQLGetCursorName(
+        statement_handle: HStmt,
+        cursor_name: *mut Char,
+        buffer_length: SmallInt,
+        name_length_ptr: *mut SmallInt,
+    ) -> SqlReturn;
+
+    /// Associates a cursor name with an active statement. If an application does not call
+    /// `SQLSetCursorName`, the driver generates cursor names as needed for SQL statement
+    /// processing.
+    ///
+    /// # Returns
+    /// `SUCCESS`, `SUCCESS_WITH_INFO`, `ERROR`, or `INVALID_HANDLE`.
+    pub fn SQLSetCursorNameW(
+        statement_handle: HStmt,
+        cursor_name: *const WChar,
+        name_length: SmallInt,
+    ) -> SqlReturn;
+
+    /// Associates a cursor name with an active statement. If an application does not call
+    /// `SQLSetCursorName`, the driver generates cursor names as needed for SQL statement
+    /// processing.
+    ///
+    /// # Returns
+    /// `SUCCESS`, `SUCCESS_WITH_INFO`, `ERROR`, or `INVALID_HANDLE`.
+    pub fn SQLSetCursorName(
+        statement_handle: HStmt,
+        cursor_name: *const Char,
+        name_length: SmallInt,
+    ) -> SqlReturn;
+
     /// Returns the current setting or value of a single field of a descriptor record.
     ///
     /// # Returns
@@ -5,7 +5,10 @@ pub enum InfoType {
     MaxDriverConnectinos = 0,
     MaxConcurrentActivities = 1,
     DataSourceName = 2,
+    DriverName = 6,
+    DriverVer = 7,
     // FetchDirection = 8, Deprecated in ODBC 3
+    OdbcVer = 10,
     ServerName = 13,
     SearchPatternEscape = 14,
     DbmsName = 17,
@@ -22,12 +25,17 @@ pub enum InfoType {
     MaxSchemaNameLen = 32,
     MaxCatalogNameLen = 34,
     MaxTableNameLen = 35,
+    SchemaTerm = 39,
+    CatalogNameSeparator = 41,
+    CatalogTerm = 42,
     // ScrollConcurrency = 43, deprecated in ODBC 3
     TransactionCapable = 46,
     UserName = 47,
     TransactionIsolationProtocol = 72,
     Integrity = 73,
+    DriverOdbcVer = 77,
     GetDataExtensions = 81,
+    BookmarkPersistence = 82,
     NullCollation = 85,
     AlterTable = 86,
     OrderByColumnsInSelect = 90,
@@ -43,6 +51,14 @@ pub enum InfoType {
     MaxTablesInSelect = 106,
     MaxUserNameLen = 107,
     OuterJoinCapabilities = 115,
+    DynamicCursorAttributes1 = 144,
+    DynamicCursorAttributes2 = 145,
+    ForwardOnlyCursorAttributes1 = 146,
+    ForwardOnlyCursorAttributes2 = 147,
+    KeysetCursorAttributes1 = 150,
+    KeysetCursorAttributes2 = 151,
+    StaticCursorAttributes1 = 167,
+    StaticCursorAttributes2 = 168,
     XopenCliYear = 10000,
     CursorSensitivity = 10001,
     DescribeParameter = 10002,
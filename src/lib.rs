@@ -10,8 +10,8 @@
 
 pub use self::{
     attributes::*, bulk_operation::*, c_data_type::*, desc::*, fetch_orientation::*, functions::*,
-    indicator::*, info_type::*, interval::*, nullability::*, param_type::*, sql_data_type::*,
-    sqlreturn::*,
+    indicator::*, info_type::*, interval::*, nullability::*, param_status::*, param_type::*,
+    row_status::*, set_pos::*, sql_data_type::*, sqlreturn::*,
 };
 use std::os::raw::{c_int, c_void};
 
@@ -25,7 +25,10 @@ mod indicator;
 mod info_type;
 mod interval;
 mod nullability;
+mod param_status;
 mod param_type;
+mod row_status;
+mod set_pos;
 mod sql_data_type;
 mod sqlreturn;
 
@@ -110,12 +113,24 @@ pub enum HandleType {
 }
 
 /// Options for `SQLDriverConnect`
+///
+/// All options other than `NoPrompt` may make the driver display a dialog box. This requires a
+/// valid parent window handle to be passed as `window_handle`. The completed connection string is
+/// returned in `out_connection_string`.
 #[repr(u16)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum DriverConnectOption {
+    /// `SQL_DRIVER_NOPROMPT`. Never prompt the user. Fail if the connection string does not
+    /// contain enough information to connect.
     NoPrompt = 0,
+    /// `SQL_DRIVER_COMPLETE`. Prompt the user only if the connection string does not contain
+    /// enough information to connect.
     Complete = 1,
+    /// `SQL_DRIVER_PROMPT`. Always prompt the user, using the values of the connection string as
+    /// initial values of the dialog.
     Prompt = 2,
+    /// `SQL_DRIVER_COMPLETE_REQUIRED`. Like `Complete`, but the user may only supply required
+    /// information.
     CompleteRequired = 3,
 }
 
@@ -210,33 +225,66 @@ pub struct Guid {
 }
 
 /// Connection attributes for `SQLSetConnectAttr`
-#[repr(i32)]
+///
+/// This is a newtype rather than an enumeration, so driver specific attributes (e.g. those defined
+/// by Microsoft SQL Server, Oracle or DB2 drivers) can be passed as `ConnectionAttribute(value)`
+/// without forking this crate.
+#[repr(C)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub enum ConnectionAttribute {
-    AsyncEnable = 4,
-    AccessMode = 101,
-    AutoCommit = 102,
-    LoginTimeout = 103,
-    Trace = 104,
-    TraceFile = 105,
-    TranslateLib = 106,
-    TranslateOption = 107,
-    TxnIsolation = 108,
-    CurrentCatalog = 109,
-    OdbcCursors = 110,
-    QuietMode = 111,
-    PacketSize = 112,
-    ConnectionTimeout = 113,
-    DisconnectBehaviour = 114,
-    AsyncDbcFunctionsEnable = 117,
-    AsyncDbcEvent = 119,
-    EnlistInDtc = 1207,
-    EnlistInXa = 1208,
-    ConnectionDead = 1209,
-    AutoIpd = 10001,
-    MetadataId = 10014,
+pub struct ConnectionAttribute(pub i32);
+
+impl ConnectionAttribute {
+    pub const ASYNC_ENABLE: ConnectionAttribute = ConnectionAttribute(4);
+    pub const ACCESS_MODE: ConnectionAttribute = ConnectionAttribute(101);
+    pub const AUTO_COMMIT: ConnectionAttribute = ConnectionAttribute(102);
+    /// `SQL_ATTR_LOGIN_TIMEOUT`. A `UInteger` value corresponding to the number of seconds to wait
+    /// for a login request to complete before returning to the application. The default is
+    /// driver-dependent. If zero, the timeout is disabled and a connection attempt will wait
+    /// indefinitely. Must be set before the connection is established.
+    pub const LOGIN_TIMEOUT: ConnectionAttribute = ConnectionAttribute(103);
+    /// `SQL_ATTR_TRACE`. Enables or disables tracing of the driver manager, see [`AttrTrace`].
+    /// Tracing is process wide, it affects all connections of the application.
+    pub const TRACE: ConnectionAttribute = ConnectionAttribute(104);
+    /// `SQL_ATTR_TRACEFILE`. A null-terminated character string containing the name of the trace
+    /// file.
+    pub const TRACE_FILE: ConnectionAttribute = ConnectionAttribute(105);
+    pub const TRANSLATE_LIB: ConnectionAttribute = ConnectionAttribute(106);
+    pub const TRANSLATE_OPTION: ConnectionAttribute = ConnectionAttribute(107);
+    pub const TXN_ISOLATION: ConnectionAttribute = ConnectionAttribute(108);
+    /// `SQL_ATTR_CURRENT_CATALOG`. A character string containing the name of the catalog to be used
+    /// by the data source. Pass the length of the string in bytes as `str_length`, or
+    /// `NTS as Integer`. If the data source does not support catalogs, the driver returns SQLSTATE
+    /// HYC00.
+    pub const CURRENT_CATALOG: ConnectionAttribute = ConnectionAttribute(109);
+    pub const ODBC_CURSORS: ConnectionAttribute = ConnectionAttribute(110);
+    pub const QUIET_MODE: ConnectionAttribute = ConnectionAttribute(111);
+    /// `SQL_ATTR_PACKET_SIZE`. A `UInteger` value specifying the network packet size in bytes. Many
+    /// data sources either do not support this option or only can return but not set the network
+    /// packet size. If set after the connection is established, the driver returns SQLSTATE HY011.
+    pub const PACKET_SIZE: ConnectionAttribute = ConnectionAttribute(112);
+    /// `SQL_ATTR_CONNECTION_TIMEOUT`. A `UInteger` value corresponding to the number of seconds to
+    /// wait for any request on the connection to complete before returning to the application. If
+    /// zero (the default), there is no timeout. The driver returns SQLSTATE HYT01 (Connection
+    /// timeout expired) once the timeout elapses.
+    pub const CONNECTION_TIMEOUT: ConnectionAttribute = ConnectionAttribute(113);
+    pub const DISCONNECT_BEHAVIOUR: ConnectionAttribute = ConnectionAttribute(114);
+    pub const ASYNC_DBC_FUNCTIONS_ENABLE: ConnectionAttribute = ConnectionAttribute(117);
+    pub const ASYNC_DBC_EVENT: ConnectionAttribute = ConnectionAttribute(119);
+    pub const ENLIST_IN_DTC: ConnectionAttribute = ConnectionAttribute(1207);
+    pub const ENLIST_IN_XA: ConnectionAttribute = ConnectionAttribute(1208);
+    /// `SQL_ATTR_CONNECTION_DEAD`. Read only `UInteger` value. Either [`CD_TRUE`] if the
+    /// connection has been lost, or [`CD_FALSE`] if it is still active. The driver reports the
+    /// state observed during the last operation, without a round trip to the server.
+    pub const CONNECTION_DEAD: ConnectionAttribute = ConnectionAttribute(1209);
+    pub const AUTO_IPD: ConnectionAttribute = ConnectionAttribute(10001);
+    pub const METADATA_ID: ConnectionAttribute = ConnectionAttribute(10014);
 }
 
+/// SQL_CD_TRUE. Value of `ConnectionAttribute::CONNECTION_DEAD` if the connection has been lost.
+pub const CD_TRUE: UInteger = 1;
+/// SQL_CD_FALSE. Value of `ConnectionAttribute::CONNECTION_DEAD` if the connection is still active.
+pub const CD_FALSE: UInteger = 0;
+
 /// `DiagIdentifier` for `SQLGetDiagField`
 #[repr(i32)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -274,20 +322,15 @@ pub enum HeaderDiagnosticIdentifier {
 }
 
 #[repr(i32)]
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
 pub enum AsyncConnectionBehavior {
     /// SQL_ASYNC_DBC_ENABLE_ON
     On = 1,
     /// SQL_ASYNC_DBC_ENABLE_OFF = 0,
+    #[default]
     Off = 0,
 }
 
-impl Default for AsyncConnectionBehavior {
-    fn default() -> AsyncConnectionBehavior {
-        AsyncConnectionBehavior::Off
-    }
-}
-
 #[repr(i32)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum DynamicDiagnosticIdentifier {
@@ -0,0 +1,22 @@
+/// Status of a set of parameters, written by the driver into the array bound with
+/// `StatementAttribute::ParamStatusPtr`.
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParamStatus(pub u16);
+
+impl ParamStatus {
+    /// `SQL_PARAM_SUCCESS`. The SQL statement was successfully executed for this set of
+    /// parameters.
+    pub const SUCCESS: ParamStatus = ParamStatus(0);
+    /// `SQL_PARAM_DIAG_UNAVAILABLE`. The driver treats arrays of parameters as a monolithic unit
+    /// and so does not generate this level of error information.
+    pub const DIAG_UNAVAILABLE: ParamStatus = ParamStatus(1);
+    /// `SQL_PARAM_ERROR`. There was an error in processing this set of parameters.
+    pub const ERROR: ParamStatus = ParamStatus(5);
+    /// `SQL_PARAM_SUCCESS_WITH_INFO`. The SQL statement was successfully executed for this set of
+    /// parameters; however, warning information is available in the diagnostics data structure.
+    pub const SUCCESS_WITH_INFO: ParamStatus = ParamStatus(6);
+    /// `SQL_PARAM_UNUSED`. This parameter set was unused, possibly due to the fact that some
+    /// previous parameter set caused an error that aborted further processing.
+    pub const UNUSED: ParamStatus = ParamStatus(7);
+}
@@ -0,0 +1,26 @@
+/// Status of a row in the rowset, written by the driver into the array bound with
+/// `StatementAttribute::RowStatusPtr`.
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RowStatus(pub u16);
+
+impl RowStatus {
+    /// `SQL_ROW_SUCCESS`. The row was successfully fetched and has not changed since it was last
+    /// fetched from this result set.
+    pub const SUCCESS: RowStatus = RowStatus(0);
+    /// `SQL_ROW_DELETED`. The row has been deleted since it was last fetched from this result set.
+    pub const DELETED: RowStatus = RowStatus(1);
+    /// `SQL_ROW_UPDATED`. The row was successfully fetched and has changed since it was last
+    /// fetched from this result set.
+    pub const UPDATED: RowStatus = RowStatus(2);
+    /// `SQL_ROW_NOROW`. The rowset overlapped the end of the result set, and no row was returned
+    /// that corresponded to this element of the row status array.
+    pub const NOROW: RowStatus = RowStatus(3);
+    /// `SQL_ROW_ADDED`. The row was inserted by `SQLBulkOperations`.
+    pub const ADDED: RowStatus = RowStatus(4);
+    /// `SQL_ROW_ERROR`. An error occurred while fetching the row.
+    pub const ERROR: RowStatus = RowStatus(5);
+    /// `SQL_ROW_SUCCESS_WITH_INFO`. The row was successfully fetched, but a warning was returned
+    /// about the row.
+    pub const SUCCESS_WITH_INFO: RowStatus = RowStatus(6);
+}
@@ -0,0 +1,34 @@
+/// Row number argument of `SQLSetPos`
+#[cfg(target_pointer_width = "64")]
+pub type SetPosIRow = u64;
+/// Row number argument of `SQLSetPos`
+#[cfg(not(target_pointer_width = "64"))]
+pub type SetPosIRow = u16;
+
+/// Operations supported by `SQLSetPos`.
+#[repr(u16)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Operation {
+    /// `SQL_POSITION`. Positions the cursor on the specified row in the rowset.
+    Position = 0,
+    /// `SQL_REFRESH`. Refreshes the buffers bound to the specified row with values from the data
+    /// source.
+    Refresh = 1,
+    /// `SQL_UPDATE`. Updates the specified row in the data source with the values of the bound
+    /// buffers.
+    Update = 2,
+    /// `SQL_DELETE`. Deletes the specified row from the data source.
+    Delete = 3,
+}
+
+/// Lock types supported by `SQLSetPos`.
+#[repr(u16)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Lock {
+    /// `SQL_LOCK_NO_CHANGE`. The row is left in whatever lock state it was in before.
+    NoChange = 0,
+    /// `SQL_LOCK_EXCLUSIVE`. Locks the row exclusively.
+    Exclusive = 1,
+    /// `SQL_LOCK_UNLOCK`. Unlocks the row.
+    Unlock = 2,
+}
@@ -66,3 +66,164 @@ fn allocate_connection_error() {
         assert_eq!(SqlReturn::SUCCESS, SQLFreeHandle(HandleType::Env, env));
     }
 }
+
+#[test]
+fn list_drivers() {
+    let mut env: Handle = null_mut();
+
+    unsafe {
+        assert_eq!(
+            SqlReturn::SUCCESS,
+            SQLAllocHandle(HandleType::Env, null_mut(), &mut env as *mut Handle)
+        );
+
+        assert_eq!(
+            SqlReturn::SUCCESS,
+            SQLSetEnvAttr(
+                env as HEnv,
+                EnvironmentAttribute::OdbcVersion,
+                AttrOdbcVersion::Odbc3.into(),
+                0
+            )
+        );
+
+        let mut description = [0u8; 256];
+        let mut description_len = 0;
+        let mut attributes = [0u8; 1024];
+        let mut attributes_len = 0;
+        let mut direction = FetchOrientation::First;
+
+        // Iterate over all installed drivers. `SQL_NO_DATA` signals we are done, or that there are
+        // no drivers installed at all.
+        loop {
+            let ret = SQLDrivers(
+                env as HEnv,
+                direction,
+                description.as_mut_ptr(),
+                description.len() as SmallInt,
+                &mut description_len,
+                attributes.as_mut_ptr(),
+                attributes.len() as SmallInt,
+                &mut attributes_len,
+            );
+            if ret == SqlReturn::NO_DATA {
+                break;
+            }
+            assert!(ret == SqlReturn::SUCCESS || ret == SqlReturn::SUCCESS_WITH_INFO);
+            direction = FetchOrientation::Next;
+        }
+
+        assert_eq!(SqlReturn::SUCCESS, SQLFreeHandle(HandleType::Env, env));
+    }
+}
+
+#[test]
+fn list_data_sources() {
+    let mut env: Handle = null_mut();
+
+    unsafe {
+        assert_eq!(
+            SqlReturn::SUCCESS,
+            SQLAllocHandle(HandleType::Env, null_mut(), &mut env as *mut Handle)
+        );
+
+        assert_eq!(
+            SqlReturn::SUCCESS,
+            SQLSetEnvAttr(
+                env as HEnv,
+                EnvironmentAttribute::OdbcVersion,
+                AttrOdbcVersion::Odbc3.into(),
+                0
+            )
+        );
+
+        let mut server_name = [0u8; 256];
+        let mut server_name_len = 0;
+        let mut description = [0u8; 256];
+        let mut description_len = 0;
+
+        // All data sources, only user data sources and only system data sources
+        for first in [
+            FetchOrientation::First,
+            FetchOrientation::FirstUser,
+            FetchOrientation::FirstSystem,
+        ] {
+            let mut direction = first;
+            loop {
+                let ret = SQLDataSources(
+                    env as HEnv,
+                    direction,
+                    server_name.as_mut_ptr(),
+                    server_name.len() as SmallInt,
+                    &mut server_name_len,
+                    description.as_mut_ptr(),
+                    description.len() as SmallInt,
+                    &mut description_len,
+                );
+                if ret == SqlReturn::NO_DATA {
+                    break;
+                }
+                assert!(ret == SqlReturn::SUCCESS || ret == SqlReturn::SUCCESS_WITH_INFO);
+                direction = FetchOrientation::Next;
+            }
+        }
+
+        assert_eq!(SqlReturn::SUCCESS, SQLFreeHandle(HandleType::Env, env));
+    }
+}
+
+#[test]
+fn environment_diagnostics() {
+    let mut env: Handle = null_mut();
+    let mut conn: Handle = null_mut();
+
+    unsafe {
+        assert_eq!(
+            SqlReturn::SUCCESS,
+            SQLAllocHandle(HandleType::Env, null_mut(), &mut env as *mut Handle)
+        );
+
+        // Fails, because ODBC version has not been set. The error is reported on the environment.
+        assert_eq!(
+            SqlReturn::ERROR,
+            SQLAllocHandle(HandleType::Dbc, env, &mut conn as *mut Handle)
+        );
+
+        let mut number_of_records: Integer = 0;
+        assert_eq!(
+            SqlReturn::SUCCESS,
+            SQLGetDiagField(
+                HandleType::Env,
+                env,
+                0,
+                HeaderDiagnosticIdentifier::Number as SmallInt,
+                &mut number_of_records as *mut Integer as Pointer,
+                0,
+                null_mut()
+            )
+        );
+        assert!(number_of_records > 0);
+
+        let mut state = [0u8; SQLSTATE_SIZE + 1];
+        let mut native_error = 0;
+        let mut message = [0u8; MAX_MESSAGE_LENGTH as usize];
+        let mut message_len = 0;
+        assert_eq!(
+            SqlReturn::SUCCESS,
+            SQLGetDiagRec(
+                HandleType::Env,
+                env,
+                1,
+                state.as_mut_ptr(),
+                &mut native_error,
+                message.as_mut_ptr(),
+                message.len() as SmallInt,
+                &mut message_len
+            )
+        );
+        // Function sequence error
+        assert_eq!(b"HY010", &state[..SQLSTATE_SIZE]);
+
+        assert_eq!(SqlReturn::SUCCESS, SQLFreeHandle(HandleType::Env, env));
+    }
+}